@@ -3,6 +3,8 @@ pub mod textures {
     pub const FLOOR_BRICK: &'static str = "hex-stone-floor";
     pub const WALL: &'static str = "hex-dirt";
     pub const WALL_BRICK: &'static str = "hex-stone";
+    pub const RAMP: &'static str = "hex-ramp";
+    pub const RAMP_BRICK: &'static str = "hex-ramp-stone";
     pub const MARKER: &'static str = "marker";
 }
 
@@ -23,6 +25,10 @@ pub const CAM_SPEED: f32 = 5.0;
 
 pub const MAX_FLOOR_HEIGHT: u8 = 2;
 pub const MAX_BRICK_HEIGHT: u8 = 4;
+pub const MAX_BRUSH_RADIUS: i32 = 8;
 
 pub const WIDTH: usize = 200;
-pub const HEIGHT: usize = 200;
\ No newline at end of file
+pub const HEIGHT: usize = 200;
+
+/// Side length, in tiles, of a `HexMap` draw-command cache block.
+pub const CHUNK_SIZE: usize = 16;
\ No newline at end of file
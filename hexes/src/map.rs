@@ -16,6 +16,8 @@ use crate::{
         },
         input::{
             InputContext,
+            Key,
+            MouseButton,
         },
     },
 };
@@ -34,9 +36,14 @@ use rand::SeedableRng;
 use rand::Rng;
 use rand::rngs::StdRng;
 
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
 pub struct HexTileData {
     pub ground_height: u8,
     pub wall_height: u8,
+    /// When set, the tile's top ramps down toward this neighbor direction instead of sitting flat.
+    pub slope: Option<HexSlope>,
 }
 
 impl HexTileData {
@@ -44,16 +51,58 @@ impl HexTileData {
         HexTileData {
             ground_height: height,
             wall_height: height,
+            slope: None,
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HexDirection {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+impl HexDirection {
+    #[allow(dead_code)]
+    fn cube_offset(self) -> (i32, i32, i32) {
+        CUBE_DIRECTIONS[self as usize]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HexSlope {
+    pub direction: HexDirection,
+    pub delta: u8,
+}
+
 pub struct HexMap {
     pub tiles: Vec<HexTileData>,
     pub width: usize,
     pub height: usize,
     pub position: Vec2<f32>,
     pub tallest: u8,
+    /// When set, `render_hex_map` draws a `textures::MARKER` at each of the four corners of the map boundary and of the culled visible region.
+    pub debug_draw_bounds: bool,
+    chunks: Vec<HexChunk>,
+    chunks_wide: usize,
+    chunks_high: usize,
+}
+
+/// A cached block of `CHUNK_SIZE * CHUNK_SIZE` tiles' worth of `DrawCommand`s, split per row so
+/// the renderer can still interleave walls/bricks/tops row-by-row for correct iso layering.
+/// Rebuilt only when `dirty`.
+struct HexChunk {
+    rows: Vec<HexChunkRow>,
+    dirty: bool,
+}
+
+struct HexChunkRow {
+    walls: Vec<DrawCommand>,
+    bricks: Vec<DrawCommand>,
 }
 
 impl HexMap {
@@ -69,13 +118,32 @@ impl HexMap {
             }
         }
 
-        HexMap {
+        let chunks_wide = (width + CHUNK_SIZE - 1) / CHUNK_SIZE;
+        let chunks_high = (height + CHUNK_SIZE - 1) / CHUNK_SIZE;
+        let chunks = (0..chunks_wide * chunks_high)
+            .map(|_| HexChunk { rows: Vec::new(), dirty: true })
+            .collect();
+
+        let mut map = HexMap {
             tiles,
             width,
             height,
             position: Vec2::new(-(width as f32) / 2.0, -(height as f32) / 2.0),
             tallest,
+            debug_draw_bounds: false,
+            chunks,
+            chunks_wide,
+            chunks_high,
+        };
+
+        // Derive ramps from the randomly generated heightmap the same way an edit would.
+        for y in 0..map.height {
+            for x in 0..map.width {
+                map.update_slope(x, y);
+            }
         }
+
+        map
     }
 
     /// Returns a hex in offset coords
@@ -119,9 +187,21 @@ impl HexMap {
             }
 
             let tile = &self.tiles[self.width * y as usize + x as usize];
-            let tile_height = tile.wall_height;
 
-            if tile_height != height {
+            // A sloped tile's ramp is drawn from `ground_height` (see `render_hex_slope`), not
+            // `wall_height`, so match against the same basis here or the cursor picks the wrong tile.
+            let slope_delta = tile.slope.as_ref().map(|s| s.delta).unwrap_or(0);
+            let tile_height = if slope_delta > 0 { tile.ground_height } else { tile.wall_height };
+
+            // A ramp's top sits somewhere between `tile_height - delta` and `tile_height`
+            // rather than at a single discrete step, so accept any height in that span.
+            let matches_height = if slope_delta > 0 {
+                height <= tile_height && height + slope_delta >= tile_height
+            } else {
+                tile_height == height
+            };
+
+            if !matches_height {
                 continue;
             }
             if tallest_height.is_none() || tile_height > tallest_height.unwrap().0 {
@@ -149,6 +229,312 @@ impl HexMap {
         let y = size_y * (3.0 / 2.0 * r);
         (x + 18. + (self.position.x * FLOOR_WIDTH), y + 18. + (self.position.y * FLOOR_VERT_STEP))
     }
+
+    /// Returns every in-bounds offset tile within `radius` hex steps of `center`, walking the
+    /// standard cube-coordinate ring spiral so the cost is O(radius) rings instead of a bounding-box scan.
+    pub fn tiles_in_radius(&self, center: (i32, i32), radius: i32) -> Vec<(i32, i32)> {
+        let (cq, cs, cr) = offset_to_cube(center.0, center.1);
+        let mut result = vec![center];
+
+        for k in 1..=radius {
+            let (start_dq, start_ds, start_dr) = CUBE_DIRECTIONS[4];
+            let (mut q, mut r, mut s) = (cq + start_dq * k, cr + start_dr * k, cs + start_ds * k);
+
+            for side in 0..6 {
+                for _ in 0..k {
+                    let (x, y) = cube_to_offset(q, r);
+                    if x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32 {
+                        result.push((x, y));
+                    }
+
+                    let (dq, ds, dr) = CUBE_DIRECTIONS[side];
+                    q += dq;
+                    r += dr;
+                    s += ds;
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the up-to-six in-bounds neighbors of an offset tile.
+    pub fn neighbors(&self, x: i32, y: i32) -> Vec<(i32, i32)> {
+        let (q, _, r) = offset_to_cube(x, y);
+        CUBE_DIRECTIONS
+            .iter()
+            .filter_map(|&(dq, _, dr)| {
+                let (nx, ny) = cube_to_offset(q + dq, r + dr);
+                if nx >= 0 && nx < self.width as i32 && ny >= 0 && ny < self.height as i32 {
+                    Some((nx, ny))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Distance in hex steps between two offset tiles.
+    pub fn hex_distance(a: (i32, i32), b: (i32, i32)) -> i32 {
+        let (aq, ar, as_) = offset_to_cube(a.0, a.1);
+        let (bq, br, bs) = offset_to_cube(b.0, b.1);
+        ((aq - bq).abs() + (ar - br).abs() + (as_ - bs).abs()) / 2
+    }
+
+    /// A* over the tile grid using `hex_distance` as the admissible heuristic. Moves between
+    /// tiles whose `wall_height` differs by more than `max_step` are rejected, and climbing
+    /// height adds to the move's cost so paths prefer gentle terrain.
+    pub fn find_path(&self, start: (i32, i32), goal: (i32, i32), max_step: u8) -> Option<Vec<(i32, i32)>> {
+        let in_bounds = |(x, y): (i32, i32)| x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32;
+        if !in_bounds(start) || !in_bounds(goal) {
+            return None;
+        }
+
+        let mut open = BinaryHeap::new();
+        open.push(Reverse((0u32, start)));
+
+        let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+        let mut g_score: HashMap<(i32, i32), u32> = HashMap::new();
+        g_score.insert(start, 0);
+
+        while let Some(Reverse((_, current))) = open.pop() {
+            if current == goal {
+                let mut path = vec![current];
+                let mut node = current;
+                while let Some(&prev) = came_from.get(&node) {
+                    path.push(prev);
+                    node = prev;
+                }
+                path.reverse();
+                return Some(path);
+            }
+
+            let current_g = g_score[&current];
+            let current_wall_height = self.tiles[self.width * current.1 as usize + current.0 as usize].wall_height;
+
+            for neighbor in self.neighbors(current.0, current.1) {
+                let neighbor_wall_height = self.tiles[self.width * neighbor.1 as usize + neighbor.0 as usize].wall_height;
+                let climb = (current_wall_height as i32 - neighbor_wall_height as i32).abs();
+                if climb > max_step as i32 {
+                    continue;
+                }
+
+                let tentative_g = current_g + 1 + climb as u32;
+                if tentative_g < *g_score.get(&neighbor).unwrap_or(&u32::MAX) {
+                    came_from.insert(neighbor, current);
+                    g_score.insert(neighbor, tentative_g);
+                    let f = tentative_g + HexMap::hex_distance(neighbor, goal) as u32;
+                    open.push(Reverse((f, neighbor)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Recomputes whether `(x, y)` should ramp down toward a lower neighbor, picking the
+    /// steepest downhill neighbor so a tile only ever slopes one way. Clears the slope when no
+    /// neighbor sits lower.
+    pub fn update_slope(&mut self, x: usize, y: usize) {
+        let ground_height = self.tiles[self.width * y + x].ground_height;
+        let (q, _, r) = offset_to_cube(x as i32, y as i32);
+
+        let mut slope = None;
+        for (i, &(dq, _, dr)) in CUBE_DIRECTIONS.iter().enumerate() {
+            let (nx, ny) = cube_to_offset(q + dq, r + dr);
+            if nx < 0 || nx >= self.width as i32 || ny < 0 || ny >= self.height as i32 {
+                continue;
+            }
+
+            let neighbor_height = self.tiles[self.width * ny as usize + nx as usize].ground_height;
+            if neighbor_height >= ground_height {
+                continue;
+            }
+
+            let delta = ground_height - neighbor_height;
+            let is_steeper = match slope {
+                Some(HexSlope { delta: best, .. }) => delta > best,
+                None => true,
+            };
+            if is_steeper {
+                slope = Some(HexSlope { direction: HEX_DIRECTIONS[i], delta });
+            }
+        }
+
+        self.tiles[self.width * y + x].slope = slope;
+    }
+
+    /// Marks the chunk containing `(x, y)` dirty, along with every neighbor tile's chunk, since
+    /// wall/brick occlusion can bleed an edit's effect into any of them.
+    pub fn mark_dirty(&mut self, x: usize, y: usize) {
+        let cx = x / CHUNK_SIZE;
+        let cy = y / CHUNK_SIZE;
+        self.dirty_chunk(cx, cy);
+
+        // Wall/brick occlusion reads all six neighbors' `wall_height`, so an edit can change
+        // the correct cached commands of any chunk one of those neighbors falls into.
+        for (nx, ny) in self.neighbors(x as i32, y as i32) {
+            self.dirty_chunk(nx as usize / CHUNK_SIZE, ny as usize / CHUNK_SIZE);
+        }
+    }
+
+    fn dirty_chunk(&mut self, cx: usize, cy: usize) {
+        if cx < self.chunks_wide && cy < self.chunks_high {
+            let index = self.chunk_index(cx, cy);
+            self.chunks[index].dirty = true;
+        }
+    }
+
+    fn chunk_index(&self, cx: usize, cy: usize) -> usize {
+        cy * self.chunks_wide + cx
+    }
+
+    /// Regenerates a chunk's cached wall and brick `DrawCommand`s row by row, in the same
+    /// row-major, wall-then-brick order the unchunked renderer used; tops are not cached here.
+    fn rebuild_chunk(&mut self, cx: usize, cy: usize, drawables: &Drawables) {
+        let (wall_tex, brick_tex) = (drawables.alias[textures::WALL], drawables.alias[textures::WALL_BRICK]);
+
+        let start_y = cy * CHUNK_SIZE;
+        let end_y = ((cy + 1) * CHUNK_SIZE).min(self.height);
+        let start_x = cx * CHUNK_SIZE;
+        let end_x = ((cx + 1) * CHUNK_SIZE).min(self.width);
+
+        let mut rows = Vec::with_capacity(end_y - start_y);
+        for y in start_y..end_y {
+            let mut walls = Vec::new();
+            for x in start_x..end_x {
+                let (draw_x, draw_y) = hex_offset_pixel(self, x, y);
+                walls.extend(render_hex_walls(self, draw_x, draw_y, x, y, wall_tex));
+            }
+
+            let mut bricks = Vec::new();
+            for x in start_x..end_x {
+                let (draw_x, draw_y) = hex_offset_pixel(self, x, y);
+                bricks.extend(render_hex_bricks(self, draw_x, draw_y, x, y, brick_tex));
+            }
+
+            rows.push(HexChunkRow { walls, bricks });
+        }
+
+        let index = self.chunk_index(cx, cy);
+        self.chunks[index] = HexChunk { rows, dirty: false };
+    }
+}
+
+const CUBE_DIRECTIONS: [(i32, i32, i32); 6] = [
+    (1, -1, 0),
+    (1, 0, -1),
+    (0, 1, -1),
+    (-1, 1, 0),
+    (-1, 0, 1),
+    (0, -1, 1),
+];
+
+const HEX_DIRECTIONS: [HexDirection; 6] = [
+    HexDirection::East,
+    HexDirection::NorthEast,
+    HexDirection::NorthWest,
+    HexDirection::West,
+    HexDirection::SouthWest,
+    HexDirection::SouthEast,
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EditMaterial {
+    Floor,
+    Brick,
+}
+
+impl EditMaterial {
+    fn toggled(self) -> EditMaterial {
+        match self {
+            EditMaterial::Floor => EditMaterial::Brick,
+            EditMaterial::Brick => EditMaterial::Floor,
+        }
+    }
+}
+
+pub struct EditorState {
+    pub brush_radius: i32,
+    pub material: EditMaterial,
+}
+
+impl Default for EditorState {
+    fn default() -> Self {
+        EditorState {
+            brush_radius: 1,
+            material: EditMaterial::Floor,
+        }
+    }
+}
+
+/// Paints terrain under the cursor: left mouse raises the brush's tiles, right mouse lowers them,
+/// Tab toggles whether the brush sculpts `ground_height` or `wall_height`, and `[`/`]` shrink or
+/// grow the brush.
+pub fn edit_hex_map(input_ctx: UniqueView<InputContext>, camera: UniqueView<Camera>, mut map: UniqueViewMut<HexMap>, mut editor: UniqueViewMut<EditorState>) {
+    if input_ctx.is_key_pressed(Key::Tab) {
+        editor.material = editor.material.toggled();
+    }
+
+    if input_ctx.is_key_pressed(Key::LeftBracket) && editor.brush_radius > 0 {
+        editor.brush_radius -= 1;
+    }
+    if input_ctx.is_key_pressed(Key::RightBracket) && editor.brush_radius < MAX_BRUSH_RADIUS {
+        editor.brush_radius += 1;
+    }
+
+    let raise = input_ctx.is_mouse_button_down(MouseButton::Left);
+    let lower = input_ctx.is_mouse_button_down(MouseButton::Right);
+    if !raise && !lower {
+        return;
+    }
+
+    let mouse_pos = camera.mouse_position(&input_ctx);
+    let selected_hex = match map.pixel_to_hex(mouse_pos) {
+        Some(hex) => hex,
+        None => return,
+    };
+
+    let brush = map.tiles_in_radius(selected_hex, editor.brush_radius);
+    let mut tallest = map.tallest;
+
+    for (x, y) in brush {
+        let tile = &mut map.tiles[map.width * y as usize + x as usize];
+
+        match editor.material {
+            EditMaterial::Floor => {
+                if raise && tile.ground_height < MAX_FLOOR_HEIGHT {
+                    tile.ground_height += 1;
+                } else if lower && tile.ground_height > 0 {
+                    tile.ground_height -= 1;
+                }
+                if tile.ground_height > tallest {
+                    tallest = tile.ground_height;
+                }
+            },
+            EditMaterial::Brick => {
+                if raise && tile.wall_height < MAX_BRICK_HEIGHT {
+                    tile.wall_height += 1;
+                } else if lower && tile.wall_height > 0 {
+                    tile.wall_height -= 1;
+                }
+                if tile.wall_height > tallest {
+                    tallest = tile.wall_height;
+                }
+            },
+        }
+
+        // `(x, y)`'s own slope depends on its height, and each neighbor's slope may now point at
+        // (or away from) `(x, y)`, so both need recomputing.
+        map.update_slope(x as usize, y as usize);
+        for (nx, ny) in map.neighbors(x, y) {
+            map.update_slope(nx as usize, ny as usize);
+        }
+
+        map.mark_dirty(x as usize, y as usize);
+    }
+
+    map.tallest = tallest;
 }
 
 fn cube_to_offset(q: i32, r: i32) -> (i32, i32) {
@@ -158,7 +544,6 @@ fn cube_to_offset(q: i32, r: i32) -> (i32, i32) {
     (col, row)
 }
 
-#[allow(dead_code)]
 fn offset_to_cube(off_x: i32, off_y: i32) -> (i32, i32, i32) {
     let x = off_x - (off_y - (off_y as i32 & 1)) / 2;
     let z = off_y;
@@ -193,61 +578,77 @@ pub fn render_hex_map(input_ctx: UniqueView<InputContext>, drawables: NonSendSyn
     let mouse_pos = camera.mouse_position(&input_ctx);
     let selected_hex = map.pixel_to_hex(mouse_pos);
 
-    let camera_pos: Vec2<f32> = camera.position / Vec2::new(FLOOR_WIDTH, FLOOR_VERT_STEP) - map.position;
+    // Inverse-project the viewport corners through the same FLOOR_WIDTH/FLOOR_VERT_STEP scale
+    // pixel_to_hex uses, then pad by how far a tall wall can rise into frame from off-screen.
+    let viewport_half = Vec2::new(
+        camera.viewport_width / camera.scale.x,
+        camera.viewport_height / camera.scale.y,
+    ) / 2.0;
+    let min_corner: Vec2<f32> = (camera.position - viewport_half) / Vec2::new(FLOOR_WIDTH, FLOOR_VERT_STEP) - map.position;
+    let max_corner: Vec2<f32> = (camera.position + viewport_half) / Vec2::new(FLOOR_WIDTH, FLOOR_VERT_STEP) - map.position;
+
+    let margin = (map.tallest as f32 * FLOOR_DEPTH_STEP / FLOOR_VERT_STEP).ceil() as i32;
+
+    let startx = (min_corner.x.floor() as i32 - margin).max(0).min(map.width as i32 - 1) as usize;
+    let endx = (max_corner.x.ceil() as i32 + margin).max(0).min(map.width as i32 - 1) as usize;
+    let starty = (min_corner.y.floor() as i32 - margin).max(0).min(map.height as i32 - 1) as usize;
+    let endy = (max_corner.y.ceil() as i32 + margin).max(0).min(map.height as i32 - 1) as usize;
+
+    let (top_tex, brick_floor_tex, ramp_tex, ramp_brick_tex) = (
+        drawables.alias[textures::FLOOR],
+        drawables.alias[textures::FLOOR_BRICK],
+        drawables.alias[textures::RAMP],
+        drawables.alias[textures::RAMP_BRICK],
+    );
+
+    // Walls/bricks are cached per chunk (regenerated only when dirty), but each row's walls,
+    // then bricks, then tops are still submitted together before moving to the next row, the
+    // same back-to-front order the original per-tile triple loop produced.
+    let start_cx = startx / CHUNK_SIZE;
+    let end_cx = endx / CHUNK_SIZE;
 
-    let startx = (camera_pos.x - 20.0).max(0.0).min(map.width as f32 - 1.0) as usize;
-    let endx = (camera_pos.x + 20.0).max(0.0).min(map.width as f32 - 1.0) as usize;
-    let starty = (camera_pos.y - 20.0).max(0.0).min(map.height as f32 - 1.0) as usize;
-    let endy = (camera_pos.y + 20.0).max(0.0).min(map.height as f32 - 1.0) as usize;
-
-    let (top_tex, wall_tex, brick_tex, brick_floor_tex) = (drawables.alias[textures::FLOOR], drawables.alias[textures::WALL], drawables.alias[textures::WALL_BRICK], drawables.alias[textures::FLOOR_BRICK]);
     for y in starty..=endy {
-        for i in 0..3 {
-            for x in startx..=endx {
-                let (draw_x, draw_y) =
-                (
-                    if y % 2 == 1 {
-                        (x as i32) as f32 * FLOOR_WIDTH + (FLOOR_WIDTH / 2.0)
-                    } else {
-                        (x as i32) as f32 * FLOOR_WIDTH
-                    },
-                    (y as i32) as f32 * (FLOOR_VERT_STEP)
-                );
-
-                let (draw_x, draw_y) =
-                    (
-                        draw_x + map.position.x * FLOOR_WIDTH,
-                        draw_y + map.position.y * FLOOR_VERT_STEP,
-                    );
-                let tile = &map.tiles[map.width * y + x];
-
-                if i == 0 {
-                    render_hex_walls(&mut draw_buffer, draw_x, draw_y, tile, wall_tex);
-                }
-                if i == 1 {
-                    render_hex_bricks(&mut draw_buffer, draw_x, draw_y, tile, brick_tex);
-                }
-                if i == 2 {
-                    let color = if let Some((sel_x, sel_y)) = selected_hex {
-                        let color = if x == sel_x as usize && y == sel_y as usize {
-                            Color::RED
-                        } else {
-                            Color::WHITE
-                        };
-                        color
-                    } else {
-                        Color::WHITE
-                    };
-
-
-                    if tile.ground_height >= tile.wall_height {
-                        render_hex_top(&mut draw_buffer, draw_x, draw_y, tile.ground_height, top_tex, color);
-                    } else {
-                        render_hex_brick_top(&mut draw_buffer, draw_x, draw_y, tile.wall_height, brick_floor_tex, color);
-                    };
-                }
+        let cy = y / CHUNK_SIZE;
+        let local_y = y - cy * CHUNK_SIZE;
+
+        for cx in start_cx..=end_cx {
+            let index = map.chunk_index(cx, cy);
+            if map.chunks[index].dirty {
+                map.rebuild_chunk(cx, cy, &drawables);
+            }
+            for command in &map.chunks[index].rows[local_y].walls {
+                draw_buffer.draw(command.clone());
             }
         }
+
+        for cx in start_cx..=end_cx {
+            let index = map.chunk_index(cx, cy);
+            for command in &map.chunks[index].rows[local_y].bricks {
+                draw_buffer.draw(command.clone());
+            }
+        }
+
+        // Floor/brick tops are drawn fresh every frame (one command per visible tile) rather
+        // than cached, since the hovered tile's highlight color would otherwise force a chunk rebuild.
+        for x in startx..=endx {
+            let (draw_x, draw_y) = hex_offset_pixel(&map, x, y);
+            let tile = &map.tiles[map.width * y + x];
+
+            let color = match selected_hex {
+                Some((sel_x, sel_y)) if x == sel_x as usize && y == sel_y as usize => Color::RED,
+                _ => Color::WHITE,
+            };
+
+            let command = if let Some(slope) = &tile.slope {
+                let ramp_tex = if tile.ground_height >= tile.wall_height { ramp_tex } else { ramp_brick_tex };
+                render_hex_slope(draw_x, draw_y, tile, slope, ramp_tex, color)
+            } else if tile.ground_height >= tile.wall_height {
+                render_hex_top(draw_x, draw_y, tile.ground_height, top_tex, color)
+            } else {
+                render_hex_brick_top(draw_x, draw_y, tile.wall_height, brick_floor_tex, color)
+            };
+            draw_buffer.draw(command);
+        }
     }
 
     // Draw dots at hex centers
@@ -268,15 +669,68 @@ pub fn render_hex_map(input_ctx: UniqueView<InputContext>, drawables: NonSendSyn
         }
     }*/
 
+    if map.debug_draw_bounds {
+        let marker_tex = drawables.alias[textures::MARKER];
+        let map_bounds = [
+            (0, 0), (map.width - 1, 0),
+            (0, map.height - 1), (map.width - 1, map.height - 1),
+        ];
+        let culled_bounds = [
+            (startx, starty), (endx, starty),
+            (startx, endy), (endx, endy),
+        ];
+
+        for &(x, y) in map_bounds.iter().chain(culled_bounds.iter()) {
+            let (draw_x, draw_y) = hex_offset_pixel(&map, x, y);
+            draw_buffer.draw(
+                DrawCommand::new(marker_tex)
+                    .position(Vec3::new(draw_x - 2.0, draw_y - 2.0, map.tallest as f32 * FLOOR_DEPTH_STEP))
+                    .draw_iso(true)
+            );
+        }
+    }
+
     draw_buffer.end_command_pool();
 }
 
-pub fn render_hex_top(draw_buffer: &mut DrawBuffer, x: f32, y: f32, height: u8, texture: u64, color: Color) {
-    let mut draw_command = create_floor_draw_cmd(x, y, height as f32 * FLOOR_DEPTH_STEP, height, texture); 
+/// Screen-space position of a tile's origin corner before any height offset is applied.
+fn hex_offset_pixel(map: &HexMap, x: usize, y: usize) -> (f32, f32) {
+    let (draw_x, draw_y) = (
+        if y % 2 == 1 {
+            (x as i32) as f32 * FLOOR_WIDTH + (FLOOR_WIDTH / 2.0)
+        } else {
+            (x as i32) as f32 * FLOOR_WIDTH
+        },
+        (y as i32) as f32 * (FLOOR_VERT_STEP)
+    );
+
+    (
+        draw_x + map.position.x * FLOOR_WIDTH,
+        draw_y + map.position.y * FLOOR_VERT_STEP,
+    )
+}
+
+pub fn render_hex_top(x: f32, y: f32, height: u8, texture: u64, color: Color) -> DrawCommand {
+    let mut draw_command = create_floor_draw_cmd(x, y, height as f32 * FLOOR_DEPTH_STEP, height, texture);
+    if color != Color::WHITE {
+        draw_command = draw_command.color(color);
+    }
+    draw_command
+}
+
+/// Draws a sloped tile's top as a single ramp-textured quad sitting at the midpoint between
+/// the tile's own height and the height it ramps down to. `slope.direction` is not read: the
+/// sprite is not oriented toward the lower neighbor.
+pub fn render_hex_slope(x: f32, y: f32, tile: &HexTileData, slope: &HexSlope, texture: u64, color: Color) -> DrawCommand {
+    let high = tile.ground_height as f32 * FLOOR_DEPTH_STEP;
+    let low = high - slope.delta as f32 * FLOOR_DEPTH_STEP;
+    let mid_height = (high + low) / 2.0;
+
+    let mut draw_command = create_floor_draw_cmd(x, y, mid_height, tile.ground_height, texture);
     if color != Color::WHITE {
         draw_command = draw_command.color(color);
     }
-    draw_buffer.draw(draw_command);
+    draw_command
 }
 
 fn create_floor_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture: u64) -> DrawCommand {
@@ -299,12 +753,12 @@ fn create_floor_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture: u64) -
         .color(color)
 }
 
-pub fn render_hex_brick_top(draw_buffer: &mut DrawBuffer, x: f32, y: f32, height: u8, texture: u64, color: Color) {
-    let mut draw_command = create_brick_floor_draw_cmd(x, y, height as f32 * FLOOR_DEPTH_STEP, height, texture); 
+pub fn render_hex_brick_top(x: f32, y: f32, height: u8, texture: u64, color: Color) -> DrawCommand {
+    let mut draw_command = create_brick_floor_draw_cmd(x, y, height as f32 * FLOOR_DEPTH_STEP, height, texture);
     if color != Color::WHITE {
         draw_command = draw_command.color(color);
     }
-    draw_buffer.draw(draw_command);
+    draw_command
 }
 
 fn create_brick_floor_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture: u64) -> DrawCommand {
@@ -330,21 +784,54 @@ fn create_brick_floor_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture:
         .color(color)
 }
 
-pub fn render_hex_walls(draw_buffer: &mut DrawBuffer, x: f32, y: f32, tile: &HexTileData, wall_tex: u64) {
-    let height = tile.ground_height as f32;
-    let start_height = height * FLOOR_DEPTH_STEP - WALL_VERT_OFFSET;
-    for i in 0..height as usize {
-        let color = 
-            if (height as usize - i) % 2 == 1 {
+/// Emits only the wall levels not already hidden by a neighbor: a tile fully surrounded by
+/// equal-or-taller neighbors (and not at the map edge, where there is nothing to occlude it)
+/// emits no side faces at all.
+pub fn render_hex_walls(map: &HexMap, x: f32, y: f32, tile_x: usize, tile_y: usize, wall_tex: u64) -> Vec<DrawCommand> {
+    let tile = &map.tiles[map.width * tile_y + tile_x];
+    let top = tile.ground_height;
+    let exposed_base = exposed_wall_base(map, tile_x as i32, tile_y as i32, tile.slope.as_ref(), top).min(top);
+
+    let start_height = top as f32 * FLOOR_DEPTH_STEP - WALL_VERT_OFFSET;
+    let levels = (top - exposed_base) as usize;
+    let mut commands = Vec::with_capacity(levels);
+    for i in 0..levels {
+        let color =
+            if (top as usize - i) % 2 == 1 {
                 1
             } else {
                 2
             };
-        
-        draw_buffer.draw(
-            create_wall_draw_cmd(x, y, start_height - (i as f32 * WALL_VERT_STEP), color, wall_tex)
-        );
+
+        commands.push(create_wall_draw_cmd(x, y, start_height - (i as f32 * WALL_VERT_STEP), color, wall_tex));
     }
+    commands
+}
+
+/// The lowest `wall_height` among a tile's six neighbors, treating an out-of-bounds neighbor
+/// (the map edge) as height 0 since there is nothing there to occlude the tile's side faces.
+fn exposed_wall_base(map: &HexMap, x: i32, y: i32, slope: Option<&HexSlope>, top: u8) -> u8 {
+    let (q, _, r) = offset_to_cube(x, y);
+    CUBE_DIRECTIONS
+        .iter()
+        .enumerate()
+        .map(|(i, &(dq, _, dr))| {
+            let (nx, ny) = cube_to_offset(q + dq, r + dr);
+            let neighbor_height = if nx >= 0 && nx < map.width as i32 && ny >= 0 && ny < map.height as i32 {
+                map.tiles[map.width * ny as usize + nx as usize].wall_height
+            } else {
+                0
+            };
+
+            match slope {
+                // Only the ramped edge's face is covered by the ramp sprite instead of a wall
+                // quad; the other five sides are occluded by their real neighbor height as usual.
+                Some(s) if s.direction as usize == i => top,
+                _ => neighbor_height,
+            }
+        })
+        .min()
+        .unwrap_or(0)
 }
 
 fn create_wall_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture: u64) -> DrawCommand {
@@ -367,16 +854,21 @@ fn create_wall_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture: u64) ->
         .color(color)
 }
 
-pub fn render_hex_bricks(draw_buffer: &mut DrawBuffer, x: f32, y: f32, tile: &HexTileData, brick_tex: u64) {
-    let start_height = tile.ground_height as f32 * FLOOR_DEPTH_STEP - WALL_VERT_STEP;
+/// Same neighbor-occlusion treatment as `render_hex_walls`, applied to the brick levels rising
+/// above `ground_height`.
+pub fn render_hex_bricks(map: &HexMap, x: f32, y: f32, tile_x: usize, tile_y: usize, brick_tex: u64) -> Vec<DrawCommand> {
+    let tile = &map.tiles[map.width * tile_y + tile_x];
+    let mut commands = Vec::new();
     if tile.wall_height > tile.ground_height {
-        for i in 1..=(tile.wall_height - tile.ground_height) {
+        let exposed_base = exposed_wall_base(map, tile_x as i32, tile_y as i32, None, tile.ground_height).max(tile.ground_height);
+        let start_height = tile.ground_height as f32 * FLOOR_DEPTH_STEP - WALL_VERT_STEP;
+
+        for i in (exposed_base - tile.ground_height + 1)..=(tile.wall_height - tile.ground_height) {
             let color = tile.ground_height + i;
-            draw_buffer.draw(
-                create_wall_brick_draw_cmd(x, y, start_height + (i as f32 * WALL_VERT_STEP), color, brick_tex)
-            );
+            commands.push(create_wall_brick_draw_cmd(x, y, start_height + (i as f32 * WALL_VERT_STEP), color, brick_tex));
         }
     }
+    commands
 }
 
 fn create_wall_brick_draw_cmd(x: f32, y: f32, height: f32, color: u8, texture: u64) -> DrawCommand {